@@ -4,7 +4,22 @@ mod stream_more;
 
 pub use compare::Compare;
 
+pub use crate::stream_more::batching::Batching;
+pub use crate::stream_more::batching::ChunkBy;
 pub use crate::stream_more::coalesce::Coalesce;
 pub use crate::stream_more::comparators;
+pub use crate::stream_more::interleave::Interleave;
+pub use crate::stream_more::interleave::PollNext;
+pub use crate::stream_more::interleave::PollStrategy;
+pub use crate::stream_more::interleave::PreferIndex;
+pub use crate::stream_more::interleave::RoundRobin;
+pub use crate::stream_more::kmerge::CoalesceBy;
+pub use crate::stream_more::kmerge::Diff;
+pub use crate::stream_more::kmerge::Intersection;
 pub use crate::stream_more::kmerge::KMerge;
+pub use crate::stream_more::kmerge::UnionDistinct;
+pub use crate::stream_more::merge_join::EitherOrBoth;
+pub use crate::stream_more::merge_join::MergeJoin;
+pub use crate::stream_more::peekable::Peekable;
+pub use crate::stream_more::peekable::PeekingTakeWhile;
 pub use crate::stream_more::StreamMore;
@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::BoxStream;
+use futures::Stream;
+
+use crate::stream_more::peekable::Peekable;
+
+/// A [`Stream`] that repeatedly calls a folder closure to build arbitrary aggregates
+/// out of a run of items, similar to `itertools::Itertools::batching`.
+///
+/// Built by [`StreamMore::batching`].
+///
+/// [`StreamMore::batching`]: crate::stream_more::StreamMore::batching
+pub struct Batching<'a, S, F, B>
+where S: Stream
+{
+    peekable: Peekable<'a, S>,
+    folder: F,
+    done: bool,
+    _item: PhantomData<B>,
+}
+
+impl<'a, S, F, B> Batching<'a, S, F, B>
+where S: Stream
+{
+    pub(crate) fn new(inner: BoxStream<'a, S::Item>, folder: F) -> Self {
+        Batching {
+            peekable: Peekable::new(inner),
+            folder,
+            done: false,
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<'a, S, F, B> Stream for Batching<'a, S, F, B>
+where
+    S: Stream,
+    S::Item: Unpin,
+    F: FnMut(&mut Peekable<'a, S>) -> Option<B> + Unpin,
+    B: Unpin,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        // The folder is a plain (non-`async`) closure, so it drives `this.peekable`
+        // through the blocking `peek_blocking`/`next_if_blocking` helpers rather than
+        // `.await`. That's fine for the streams this adaptor targets (eager sources
+        // like `futures::stream::iter`); see `Peekable::peek_blocking`.
+        match (this.folder)(&mut this.peekable) {
+            Some(batch) => Poll::Ready(Some(batch)),
+            None => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use futures::executor::block_on;
+use futures::stream::iter;
+use futures::StreamExt;
+
+use crate::StreamMore;
+
+#[test]
+fn test_batching_empty() -> anyhow::Result<()> {
+    let data = iter(Vec::<u64>::new());
+    let got = block_on(
+        data.batching(|p| {
+            let first = p.next_if_blocking(|_| true)?;
+            Some(vec![first])
+        })
+        .collect::<Vec<_>>(),
+    );
+    assert_eq!(Vec::<Vec<u64>>::new(), got);
+    Ok(())
+}
+
+#[test]
+fn test_batching_pairs() -> anyhow::Result<()> {
+    // Group items two at a time, like `itertools`' `batching` example.
+    let data = iter(vec![1, 2, 3, 4, 5]);
+    let got = block_on(
+        data.batching(|p| {
+            let first = p.next_if_blocking(|_| true)?;
+            let mut batch = vec![first];
+            if let Some(second) = p.next_if_blocking(|_| true) {
+                batch.push(second);
+            }
+            Some(batch)
+        })
+        .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], got);
+    Ok(())
+}
+
+#[test]
+fn test_batching_runs_while_increasing() -> anyhow::Result<()> {
+    let data = iter(vec![1, 2, 3, 1, 2, 5, 0]);
+    let got = block_on(
+        data.batching(|p| {
+            let first = p.next_if_blocking(|_| true)?;
+            let mut run = vec![first];
+            while let Some(&last) = run.last() {
+                match p.next_if_blocking(|x| *x > last) {
+                    Some(next) => run.push(next),
+                    None => break,
+                }
+            }
+            Some(run)
+        })
+        .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(vec![vec![1, 2, 3], vec![1, 2, 5], vec![0]], got);
+    Ok(())
+}
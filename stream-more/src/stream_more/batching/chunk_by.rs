@@ -0,0 +1,81 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::BoxStream;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// A [`Stream`] that groups consecutive items sharing the same key, emitting
+    /// `(key, items)` for each maximal run.
+    ///
+    /// Built by [`StreamMore::chunk_by`].
+    ///
+    /// [`StreamMore::chunk_by`]: crate::stream_more::StreamMore::chunk_by
+    pub struct ChunkBy<'a, T, K, F> {
+        #[pin]
+        group: Option<(K, Vec<T>)>,
+        finished: bool,
+        inner: BoxStream<'a, T>,
+        key_fn: F,
+    }
+}
+
+impl<'a, T, K, F> ChunkBy<'a, T, K, F>
+where F: FnMut(&T) -> K
+{
+    pub(crate) fn new(stream: BoxStream<'a, T>, key_fn: F) -> Self {
+        ChunkBy {
+            group: None,
+            finished: false,
+            inner: stream,
+            key_fn,
+        }
+    }
+}
+
+impl<'a, T, K, F> Stream for ChunkBy<'a, T, K, F>
+where
+    T: Unpin,
+    K: Unpin + PartialEq,
+    F: FnMut(&T) -> K,
+{
+    type Item = (K, Vec<T>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        let mut this = self.project();
+        loop {
+            let next = ready!(this.inner.as_mut().poll_next(cx));
+
+            let item = match next {
+                Some(item) => item,
+                None => {
+                    *this.finished = true;
+                    return Poll::Ready(this.group.take());
+                }
+            };
+
+            let key = (this.key_fn)(&item);
+
+            match this.group.take() {
+                None => {
+                    *this.group = Some((key, vec![item]));
+                }
+                Some((group_key, mut items)) if group_key == key => {
+                    items.push(item);
+                    *this.group = Some((group_key, items));
+                }
+                Some(finished_group) => {
+                    *this.group = Some((key, vec![item]));
+                    return Poll::Ready(Some(finished_group));
+                }
+            }
+        }
+    }
+}
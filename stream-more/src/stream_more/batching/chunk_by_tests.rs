@@ -0,0 +1,64 @@
+use std::task::Context;
+use std::task::Poll;
+
+use futures::executor::block_on;
+use futures::stream;
+use futures::stream::iter;
+use futures::StreamExt;
+
+use crate::StreamMore;
+
+#[test]
+fn test_chunk_by_empty() -> anyhow::Result<()> {
+    let data = iter(Vec::<u64>::new());
+    let got = block_on(data.chunk_by(|x| *x).collect::<Vec<_>>());
+    assert_eq!(Vec::<(u64, Vec<u64>)>::new(), got);
+    Ok(())
+}
+
+#[test]
+fn test_chunk_by_basic() -> anyhow::Result<()> {
+    let data = iter(vec![1, 1, 2, 2, 2, 3, 1]);
+    let got = block_on(data.chunk_by(|x| *x).collect::<Vec<_>>());
+
+    assert_eq!(
+        vec![(1, vec![1, 1]), (2, vec![2, 2, 2]), (3, vec![3]), (1, vec![1])],
+        got
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chunk_by_does_not_lose_boundary_item() -> anyhow::Result<()> {
+    let data = iter(vec!["a", "ab", "b", "ba"]);
+    let got = block_on(data.chunk_by(|s| s.chars().next().unwrap()).collect::<Vec<_>>());
+
+    assert_eq!(vec![('a', vec!["a", "ab"]), ('b', vec!["b", "ba"])], got);
+    Ok(())
+}
+
+#[test]
+fn test_chunk_by_pending_does_not_close_group() -> anyhow::Result<()> {
+    use Poll::Pending;
+    use Poll::Ready;
+
+    let mut poll_results = vec![Ready(Some(1)), Pending, Ready(Some(1)), Ready(Some(2)), Ready(None)];
+    let x = stream::poll_fn(move |ctx: &mut Context<'_>| {
+        ctx.waker().wake_by_ref();
+        poll_results.remove(0)
+    });
+
+    let got = block_on(x.chunk_by(|x: &u64| *x).collect::<Vec<_>>());
+    assert_eq!(vec![(1, vec![1, 1]), (2, vec![2])], got);
+    Ok(())
+}
+
+#[test]
+fn test_chunk_by_no_more_polling_after_none() -> anyhow::Result<()> {
+    let mut poll_results = vec![Poll::Ready(Some(1)), Poll::Ready(None), Poll::Ready(Some(9))];
+    let x = stream::poll_fn(move |_ctx: &mut Context<'_>| poll_results.remove(0));
+
+    let got = block_on(x.chunk_by(|x: &u64| *x).collect::<Vec<_>>());
+    assert_eq!(vec![(1, vec![1])], got);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)] mod batching;
+mod chunk_by;
+pub use batching::Batching;
+pub use chunk_by::ChunkBy;
+
+#[cfg(test)] mod batching_tests;
+#[cfg(test)] mod chunk_by_tests;
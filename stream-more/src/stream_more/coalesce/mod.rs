@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)] mod coalesce;
+pub use coalesce::Coalesce;
+
+#[cfg(test)] mod coalesce_tests;
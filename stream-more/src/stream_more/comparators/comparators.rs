@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+
+use compare::Compare;
+
+/// Sort merge in descending order
+#[derive(Clone, Copy)]
+pub struct Descending;
+
+impl<T> Compare<T> for Descending
+where T: Ord
+{
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        l.cmp(r)
+    }
+}
+
+/// Sort merge in ascending order
+#[derive(Clone, Copy)]
+pub struct Ascending;
+
+impl<T> Compare<T> for Ascending
+where T: Ord
+{
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        r.cmp(l)
+    }
+}
+
+/// A wrapper of choosing function `Fn(&D, &D) -> bool` to implement `Compare<D>`.
+#[derive(Clone, Copy)]
+pub struct FnCmp<F>(pub F);
+
+impl<D, F> Compare<D> for FnCmp<F>
+where F: Fn(&D, &D) -> bool
+{
+    fn compare(&self, l: &D, r: &D) -> Ordering {
+        if self.0(l, r) {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+/// Compare strings the way humans expect version/filename sequences to sort: runs of
+/// digits compare numerically rather than character-by-character, so `"item2"` sorts
+/// before `"item10"` and `"v1.9"` before `"v1.10"`.
+///
+/// Follows the same min-first convention as [`Ascending`]: the naturally smaller value
+/// compares as `Greater`, so a `KMerge` built from this comparator pops it first.
+///
+/// # Example
+///
+/// ```
+/// use compare::Compare;
+/// use std::cmp::Ordering;
+/// use stream_more::comparators::Natural;
+///
+/// assert_eq!(Ordering::Greater, Natural.compare(&"item2", &"item10"));
+/// assert_eq!(Ordering::Greater, Natural.compare(&"v1.9", &"v1.10"));
+/// assert_eq!(Ordering::Equal, Natural.compare(&"v0", &"v00"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Natural;
+
+impl<T> Compare<T> for Natural
+where T: AsRef<str>
+{
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        natural_cmp(r.as_ref(), l.as_ref())
+    }
+}
+
+fn natural_cmp(l: &str, r: &str) -> Ordering {
+    let mut l = l.chars().peekable();
+    let mut r = r.chars().peekable();
+
+    loop {
+        return match (l.peek().copied(), r.peek().copied()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(cl), Some(cr)) if cl.is_ascii_digit() && cr.is_ascii_digit() => {
+                match natural_cmp_digit_run(take_digit_run(&mut l), take_digit_run(&mut r)) {
+                    Ordering::Equal => continue,
+                    ord => ord,
+                }
+            }
+            (Some(cl), Some(cr)) => match cl.cmp(&cr) {
+                Ordering::Equal => {
+                    l.next();
+                    r.next();
+                    continue;
+                }
+                ord => ord,
+            },
+        };
+    }
+}
+
+/// Consume the longest run of ASCII digits at the front of `chars`.
+fn take_digit_run(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits.push(c);
+        chars.next();
+    }
+    digits
+}
+
+/// Compare two digit runs numerically: strip leading zeros, then compare by length
+/// (more digits is a bigger number) and finally lexically.
+fn natural_cmp_digit_run(l: String, r: String) -> Ordering {
+    let l = l.trim_start_matches('0');
+    let r = r.trim_start_matches('0');
+
+    l.len().cmp(&r.len()).then_with(|| l.cmp(r))
+}
+
+/// A [`Compare`] that projects a key out of each item with `F` and delegates to an
+/// inner comparator.
+///
+/// Built by [`by_key`] or [`by_key_with`].
+#[derive(Clone, Copy)]
+pub struct ByKey<F, C> {
+    extractor: F,
+    inner: C,
+}
+
+impl<D, K, F, C> Compare<D> for ByKey<F, C>
+where
+    F: Fn(&D) -> &K,
+    C: Compare<K>,
+{
+    fn compare(&self, l: &D, r: &D) -> Ordering {
+        self.inner.compare((self.extractor)(l), (self.extractor)(r))
+    }
+}
+
+/// Build a [`Compare`] that orders items ascending by a key projected out with
+/// `extractor`.
+///
+/// # Example
+///
+/// ```
+/// use compare::Compare;
+/// use std::cmp::Ordering;
+/// use stream_more::comparators::by_key;
+///
+/// struct Event {
+///     timestamp: u64,
+/// }
+///
+/// // As with `Ascending` itself, the smaller key "wins" (`Greater`), so a `KMerge`
+/// // built from this comparator pops its smallest timestamp first.
+/// let cmp = by_key(|e: &Event| &e.timestamp);
+/// assert_eq!(Ordering::Greater, cmp.compare(&Event { timestamp: 1 }, &Event { timestamp: 2 }));
+/// ```
+pub fn by_key<D, K, F>(extractor: F) -> ByKey<F, Ascending>
+where F: Fn(&D) -> &K {
+    ByKey {
+        extractor,
+        inner: Ascending,
+    }
+}
+
+/// Like [`by_key`], but delegating to `inner` instead of the default [`Ascending`]
+/// order, e.g. to sort descending or to compose with [`Compare::then`].
+///
+/// # Example
+///
+/// ```
+/// use compare::Compare;
+/// use std::cmp::Ordering;
+/// use stream_more::comparators::by_key_with;
+/// use stream_more::comparators::Descending;
+///
+/// struct Event {
+///     timestamp: u64,
+/// }
+///
+/// let cmp = by_key_with(|e: &Event| &e.timestamp, Descending);
+/// assert_eq!(Ordering::Less, cmp.compare(&Event { timestamp: 1 }, &Event { timestamp: 2 }));
+/// ```
+pub fn by_key_with<D, K, F, C>(extractor: F, inner: C) -> ByKey<F, C>
+where
+    F: Fn(&D) -> &K,
+    C: Compare<K>,
+{
+    ByKey { extractor, inner }
+}
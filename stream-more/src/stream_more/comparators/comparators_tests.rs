@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use compare::Compare;
+
+use crate::stream_more::comparators::by_key;
+use crate::stream_more::comparators::by_key_with;
+use crate::stream_more::comparators::Descending;
+use crate::stream_more::comparators::Natural;
+
+#[derive(Clone, Copy)]
+struct Record {
+    key: i32,
+    seq: i32,
+}
+
+struct ByFirst;
+impl Compare<(i32, i32)> for ByFirst {
+    fn compare(&self, l: &(i32, i32), r: &(i32, i32)) -> Ordering {
+        l.0.cmp(&r.0)
+    }
+}
+
+struct BySecond;
+impl Compare<(i32, i32)> for BySecond {
+    fn compare(&self, l: &(i32, i32), r: &(i32, i32)) -> Ordering {
+        l.1.cmp(&r.1)
+    }
+}
+
+#[test]
+fn test_then_falls_through_on_equal() -> anyhow::Result<()> {
+    let cmp = ByFirst.then(BySecond);
+
+    // First elements tie, so the second comparator decides.
+    assert_eq!(Ordering::Less, cmp.compare(&(1, 2), &(1, 5)));
+    assert_eq!(Ordering::Equal, cmp.compare(&(1, 2), &(1, 2)));
+    Ok(())
+}
+
+#[test]
+fn test_then_short_circuits_on_not_equal() -> anyhow::Result<()> {
+    let cmp = ByFirst.then(BySecond);
+
+    // First elements already differ, so the second comparator is never consulted.
+    assert_eq!(Ordering::Greater, cmp.compare(&(2, 0), &(1, 100)));
+    Ok(())
+}
+
+#[test]
+fn test_natural_orders_digit_runs_numerically() -> anyhow::Result<()> {
+    // `Natural` is min-first, like `Ascending`: the naturally smaller value compares
+    // as `Greater`.
+    assert_eq!(Ordering::Greater, Natural.compare(&"item2", &"item10"));
+    assert_eq!(Ordering::Greater, Natural.compare(&"v1.9", &"v1.10"));
+    assert_eq!(Ordering::Less, Natural.compare(&"item10", &"item2"));
+    Ok(())
+}
+
+#[test]
+fn test_natural_leading_zeros() -> anyhow::Result<()> {
+    assert_eq!(Ordering::Equal, Natural.compare(&"0", &"00"));
+    assert_eq!(Ordering::Greater, Natural.compare(&"a01", &"a1.5"));
+    Ok(())
+}
+
+#[test]
+fn test_natural_falls_back_to_lexical_for_text_runs() -> anyhow::Result<()> {
+    assert_eq!(Ordering::Greater, Natural.compare(&"abc", &"abd"));
+    assert_eq!(Ordering::Equal, Natural.compare(&"same", &"same"));
+    Ok(())
+}
+
+#[test]
+fn test_by_key_defaults_to_ascending() -> anyhow::Result<()> {
+    let cmp = by_key(|r: &Record| &r.key);
+
+    let a = Record { key: 1, seq: 0 };
+    let b = Record { key: 2, seq: 0 };
+    // `Ascending` makes the smaller key win, same as the bare `Ascending` comparator.
+    assert_eq!(Ordering::Greater, cmp.compare(&a, &b));
+    Ok(())
+}
+
+#[test]
+fn test_by_key_with_custom_inner_comparator() -> anyhow::Result<()> {
+    let cmp = by_key_with(|r: &Record| &r.key, Descending);
+
+    let a = Record { key: 1, seq: 0 };
+    let b = Record { key: 2, seq: 0 };
+    assert_eq!(Ordering::Less, cmp.compare(&a, &b));
+    Ok(())
+}
+
+#[test]
+fn test_by_key_composes_with_then() -> anyhow::Result<()> {
+    let cmp = by_key(|r: &Record| &r.key).then(by_key(|r: &Record| &r.seq));
+
+    // Same key, so the run falls through to comparing `seq`.
+    let a = Record { key: 1, seq: 1 };
+    let b = Record { key: 1, seq: 2 };
+    assert_eq!(Ordering::Greater, cmp.compare(&a, &b));
+    Ok(())
+}
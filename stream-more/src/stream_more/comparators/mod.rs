@@ -0,0 +1,12 @@
+//! [`Compare`] implementations
+
+#[allow(clippy::module_inception)] mod comparators;
+pub use comparators::by_key;
+pub use comparators::by_key_with;
+pub use comparators::Ascending;
+pub use comparators::ByKey;
+pub use comparators::Descending;
+pub use comparators::FnCmp;
+pub use comparators::Natural;
+
+#[cfg(test)] mod comparators_tests;
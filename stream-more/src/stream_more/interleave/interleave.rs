@@ -0,0 +1,175 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::BoxStream;
+use futures::Stream;
+
+/// Which source an unordered merge should try first in a round.
+///
+/// For the common two-source case this is `Left`/`Right`; [`PollNext::Index`]
+/// generalizes it to any number of sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollNext {
+    /// Try the first (index `0`) source first.
+    Left,
+
+    /// Try the second (index `1`) source first.
+    Right,
+
+    /// Try the source at this index first.
+    Index(usize),
+}
+
+impl PollNext {
+    fn as_index(self) -> usize {
+        match self {
+            PollNext::Left => 0,
+            PollNext::Right => 1,
+            PollNext::Index(i) => i,
+        }
+    }
+}
+
+/// Decides, each round, which of several live sources an [`Interleave`] should try
+/// first.
+///
+/// Unlike [`Compare`](compare::Compare), a strategy never looks at the items
+/// themselves, only at how many sources are currently live, which is what lets
+/// `Interleave` combine streams that aren't sorted.
+pub trait PollStrategy {
+    /// Return the source that should be polled first, given there are `len` (`> 0`)
+    /// live sources remaining.
+    fn pick(&mut self, len: usize) -> PollNext;
+
+    /// Called once a dead source at `removed_idx` has been dropped from the rotation
+    /// via `swap_remove`, leaving `new_len` sources.
+    ///
+    /// `swap_remove` moves whatever lived at index `new_len` (the old last index, if
+    /// it wasn't `removed_idx` itself) into `removed_idx`, so a strategy that
+    /// remembers an absolute index across polls can use this to keep it pointing at
+    /// the source it expects, instead of drifting onto an unrelated or dead slot.
+    fn on_removed(&mut self, _removed_idx: usize, _new_len: usize) {}
+}
+
+/// A [`PollStrategy`] that starts each round one source after the one it started the
+/// previous round with, so that no single source can starve the others.
+///
+/// The cursor advances on every round, including a round in which the picked source
+/// was not ready and another source's item was returned instead; it does not track
+/// which source last produced an item.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RoundRobin {
+    last: usize,
+}
+
+impl PollStrategy for RoundRobin {
+    fn pick(&mut self, len: usize) -> PollNext {
+        let next = (self.last + 1) % len;
+        self.last = next;
+        PollNext::Index(next)
+    }
+
+    fn on_removed(&mut self, removed_idx: usize, new_len: usize) {
+        if new_len == 0 {
+            self.last = 0;
+        } else if self.last == new_len {
+            // `swap_remove` moved the old last source (previously at `new_len`, the
+            // only index `swap_remove` ever relocates) into `removed_idx`.
+            self.last = removed_idx;
+        } else if self.last > new_len {
+            self.last %= new_len;
+        }
+    }
+}
+
+/// A [`PollStrategy`] that always tries the same source index first, falling back to
+/// the others only when it has nothing ready.
+#[derive(Debug, Clone, Copy)]
+pub struct PreferIndex(pub usize);
+
+impl PollStrategy for PreferIndex {
+    fn pick(&mut self, len: usize) -> PollNext {
+        PollNext::Index(self.0 % len)
+    }
+}
+
+/// A [`Stream`] that fans-in several sources in no particular order, guided by a
+/// [`PollStrategy`] instead of a comparator.
+///
+/// Unlike [`KMerge`], the sources don't need to be sorted: each round, the strategy
+/// picks which source to try first, every live source is polled in that order, and the
+/// first one that is `Ready` wins. `Interleave` only returns `Pending` once *every* live
+/// source does, so one slow source can't starve the others.
+///
+/// [`KMerge`]: crate::stream_more::kmerge::KMerge
+pub struct Interleave<'a, D, P> {
+    sources: Vec<BoxStream<'a, D>>,
+    strategy: P,
+}
+
+impl<'a, D, P> Interleave<'a, D, P>
+where P: PollStrategy
+{
+    /// Create an empty `Interleave` that will pick its next source using `strategy`.
+    pub fn with_strategy(strategy: P) -> Self {
+        Interleave {
+            sources: Vec::new(),
+            strategy,
+        }
+    }
+
+    /// Append another source to the merge.
+    ///
+    /// This method can be called any time after the stream is created.
+    pub fn merge(mut self, stream: impl Stream<Item = D> + Send + 'a) -> Self {
+        self.sources.push(Box::pin(stream));
+        self
+    }
+}
+
+impl<'a, D, P> Stream for Interleave<'a, D, P>
+where
+    D: Unpin,
+    P: PollStrategy + Unpin,
+{
+    type Item = D;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let len = this.sources.len();
+            if len == 0 {
+                return Poll::Ready(None);
+            }
+
+            let start = this.strategy.pick(len).as_index() % len;
+            let mut exhausted = None;
+
+            for offset in 0..len {
+                let idx = (start + offset) % len;
+                match this.sources[idx].as_mut().poll_next(cx) {
+                    Poll::Ready(Some(v)) => return Poll::Ready(Some(v)),
+                    Poll::Ready(None) => {
+                        exhausted = Some(idx);
+                        break;
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            match exhausted {
+                // Drop the dead source and retry the round with one fewer source. Use
+                // `swap_remove` rather than `remove` so at most one other source
+                // changes index, and tell the strategy about it so a cursor it
+                // remembers across polls doesn't drift onto an unrelated or dead slot.
+                Some(idx) => {
+                    let _ = this.sources.swap_remove(idx);
+                    this.strategy.on_removed(idx, this.sources.len());
+                }
+                None => return Poll::Pending,
+            }
+        }
+    }
+}
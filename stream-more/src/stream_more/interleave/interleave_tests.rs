@@ -0,0 +1,81 @@
+use std::task::Context;
+use std::task::Poll;
+
+use futures::executor::block_on;
+use futures::stream;
+use futures::stream::iter;
+use futures::StreamExt;
+
+use crate::stream_more::interleave::Interleave;
+use crate::stream_more::interleave::PreferIndex;
+use crate::stream_more::interleave::RoundRobin;
+use crate::stream_more::StreamMore;
+
+#[test]
+fn test_interleave_round_robin() -> anyhow::Result<()> {
+    let x = iter([1, 3, 5]);
+    let y = iter([2, 4]);
+
+    let z = x.interleave(y);
+    let got = block_on(z.collect::<Vec<_>>());
+
+    // Every source is tried in turn; once `y` runs out `x` drains the rest.
+    assert_eq!(vec![2, 1, 4, 3, 5], got);
+    Ok(())
+}
+
+#[test]
+fn test_interleave_prefer_index() -> anyhow::Result<()> {
+    let x = iter([1, 2, 3]);
+    let y = iter([10, 20]);
+
+    let z = Interleave::with_strategy(PreferIndex(0)).merge(x).merge(y);
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(vec![1, 2, 3, 10, 20], got);
+    Ok(())
+}
+
+#[test]
+fn test_interleave_one_slow_source_does_not_starve_others() -> anyhow::Result<()> {
+    use Poll::Pending;
+    use Poll::Ready;
+
+    let mut poll_results = vec![Pending, Ready(Some(8)), Pending, Ready(Some(4)), Ready(None)];
+    let slow = stream::poll_fn(move |ctx: &mut Context<'_>| {
+        ctx.waker().wake_by_ref();
+        poll_results.remove(0)
+    });
+
+    let fast = iter([1, 2]);
+
+    let z = Interleave::with_strategy(RoundRobin::default()).merge(slow).merge(fast);
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(vec![1, 2, 8, 4], got);
+    Ok(())
+}
+
+#[test]
+fn test_interleave_round_robin_source_ending_mid_rotation() -> anyhow::Result<()> {
+    // `b` has a single item and ends on the very round it would be tried first, forcing
+    // `RoundRobin` to drop it (via `swap_remove`, which relocates `c`) and keep cycling
+    // fairly between the two survivors rather than drifting onto a dead or wrong slot.
+    let a = iter([10u64, 11, 12]);
+    let b = iter([20u64]);
+    let c = iter([30u64, 31, 32]);
+
+    let z = Interleave::with_strategy(RoundRobin::default()).merge(a).merge(b).merge(c);
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(vec![20, 30, 10, 11, 31, 12, 32], got);
+    Ok(())
+}
+
+#[test]
+fn test_interleave_empty() -> anyhow::Result<()> {
+    let z = Interleave::<u64, RoundRobin>::with_strategy(RoundRobin::default());
+    let got = block_on(z.collect::<Vec<_>>());
+    assert_eq!(Vec::<u64>::new(), got);
+    Ok(())
+}
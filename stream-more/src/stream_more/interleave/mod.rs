@@ -0,0 +1,8 @@
+#[allow(clippy::module_inception)] mod interleave;
+pub use interleave::Interleave;
+pub use interleave::PollNext;
+pub use interleave::PollStrategy;
+pub use interleave::PreferIndex;
+pub use interleave::RoundRobin;
+
+#[cfg(test)] mod interleave_tests;
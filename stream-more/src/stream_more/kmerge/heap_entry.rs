@@ -37,6 +37,12 @@ impl<'a, D> HeapEntry<'a, D> {
         self.id = id.to_string();
         self
     }
+
+    /// The merge order this entry was added in, i.e. `id` parsed back to a number, used
+    /// to break ties when [`HeapEntryCmp`] is in stable mode.
+    pub(crate) fn seq(&self) -> u64 {
+        self.id.parse().unwrap_or(u64::MAX)
+    }
 }
 
 /// A [`Compare`] implementation that compares [`Peeked`] values and its containing struct
@@ -47,14 +53,34 @@ impl<'a, D> HeapEntry<'a, D> {
 ///
 /// If two values are both `Peeked::Yes(_)`, then it will compare the inner values by the
 /// `Compare<D>` implementation.
+///
+/// If that also results in `Equal` and `stable` is set, the entry added earlier (lower
+/// [`HeapEntry::seq`]) wins, giving [`KMerge::stable`] a deterministic output order for
+/// ties.
+///
+/// [`KMerge::stable`]: crate::stream_more::kmerge::KMerge::stable
 pub struct HeapEntryCmp<D, C: Compare<D>> {
     pub cmp: C,
+
+    /// Whether ties between two `Peeked::Yes(_)` entries fall back to comparing
+    /// [`HeapEntry::seq`].
+    pub stable: bool,
+
     _p: PhantomData<D>,
 }
 
 impl<D, C: Compare<D>> HeapEntryCmp<D, C> {
     pub fn new(cmp: C) -> Self {
-        Self { cmp, _p: PhantomData }
+        Self {
+            cmp,
+            stable: false,
+            _p: PhantomData,
+        }
+    }
+
+    pub fn with_stable(mut self, stable: bool) -> Self {
+        self.stable = stable;
+        self
     }
 }
 
@@ -71,6 +97,16 @@ impl<D, C: Compare<D>> Compare<Peeked<D>> for HeapEntryCmp<D, C> {
 
 impl<'a, D, C: Compare<D>> Compare<HeapEntry<'a, D>> for HeapEntryCmp<D, C> {
     fn compare(&self, l: &HeapEntry<D>, r: &HeapEntry<D>) -> Ordering {
-        self.compare(&l.peeked, &r.peeked)
+        let ord = self.compare(&l.peeked, &r.peeked);
+
+        if ord != Ordering::Equal || !self.stable {
+            return ord;
+        }
+
+        match (&l.peeked, &r.peeked) {
+            // Both entries produced an equal item: the one added first wins.
+            (Peeked::Yes(_), Peeked::Yes(_)) => r.seq().cmp(&l.seq()),
+            _ => ord,
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
@@ -8,6 +10,8 @@ use compare::Compare;
 use futures::ready;
 use futures::Stream;
 
+use crate::stream_more::comparators::Ascending;
+use crate::stream_more::comparators::Descending;
 use crate::stream_more::comparators::FnCmp;
 use crate::stream_more::kmerge::heap_entry::HeapEntry;
 use crate::stream_more::kmerge::heap_entry::HeapEntryCmp;
@@ -44,6 +48,14 @@ pub struct KMerge<'a, C, D>
 where C: Compare<D>
 {
     curr_id: u64,
+
+    /// A copy of the comparator the heap was built with.
+    ///
+    /// The heap only ever compares `HeapEntry`s for ordering; the set-algebra adaptors
+    /// below([`UnionDistinct`], [`Intersection`], [`Diff`]) also need to compare two
+    /// already-peeked values directly, so a copy is kept alongside the heap.
+    cmp: C,
+
     heap: BinaryHeap<HeapEntry<'a, D>, HeapEntryCmp<D, C>>,
 }
 
@@ -74,11 +86,58 @@ where
     /// let got = block_on(m.collect::<Vec<u64>>());
     /// assert_eq!(vec![1, 2, 3, 4], got);
     /// ```
-    pub fn by(first: F) -> Self {
+    pub fn by(first: F) -> Self
+    where F: Clone {
         Self::by_cmp(FnCmp(first))
     }
 }
 
+impl<'a, D> KMerge<'a, Descending, D>
+where Descending: Compare<D>
+{
+    /// Return an empty `Stream` adaptor `KMerge` that merges streams by choosing the
+    /// maximum item, behaving like a max-heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::KMerge;
+    ///
+    /// let m = KMerge::max().merge(iter([3, 1])).merge(iter([4, 2]));
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![4, 3, 2, 1], got);
+    /// ```
+    pub fn max() -> Self {
+        Self::by_cmp(Descending)
+    }
+}
+
+impl<'a, D> KMerge<'a, Ascending, D>
+where Ascending: Compare<D>
+{
+    /// Return an empty `Stream` adaptor `KMerge` that merges streams by choosing the
+    /// minimum item, behaving like a min-heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::KMerge;
+    ///
+    /// let m = KMerge::min().merge(iter([3, 1])).merge(iter([4, 2]));
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![3, 1, 4, 2], got);
+    /// ```
+    pub fn min() -> Self {
+        Self::by_cmp(Ascending)
+    }
+}
+
 impl<'a, D, C> KMerge<'a, C, D>
 where C: Compare<D>
 {
@@ -100,9 +159,11 @@ where C: Compare<D>
     /// let got = block_on(m.collect::<Vec<u64>>());
     /// assert_eq!(vec![1, 2, 3, 4], got);
     /// ```
-    pub fn by_cmp(cmp: C) -> Self {
+    pub fn by_cmp(cmp: C) -> Self
+    where C: Clone {
         KMerge {
             curr_id: 0,
+            cmp: cmp.clone(),
             heap: BinaryHeap::<HeapEntry<D>, _>::from_vec_cmp(vec![], HeapEntryCmp::new(cmp)),
         }
     }
@@ -115,6 +176,40 @@ where C: Compare<D>
         self.heap.push(HeapEntry::new(Box::pin(stream)).with_id(self.curr_id));
         self
     }
+
+    /// Make the merge order deterministic: when two streams' peeked items compare
+    /// `Equal` under `C`, the one that was `merge`d earlier is output first.
+    ///
+    /// Without this, the relative order of equal items from different streams is
+    /// unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::iter;
+    /// use futures::StreamExt;
+    /// use stream_more::comparators::Ascending;
+    /// use stream_more::KMerge;
+    ///
+    /// let m = KMerge::by_cmp(Ascending)
+    ///     .merge(iter([(1, "a")]))
+    ///     .merge(iter([(1, "b")]))
+    ///     .stable();
+    /// let got = block_on(m.collect::<Vec<_>>());
+    /// assert_eq!(vec![(1, "a"), (1, "b")], got);
+    /// ```
+    pub fn stable(self) -> Self
+    where C: Clone {
+        let KMerge { curr_id, cmp, heap } = self;
+        let items = heap.into_vec();
+
+        KMerge {
+            curr_id,
+            cmp: cmp.clone(),
+            heap: BinaryHeap::from_vec_cmp(items, HeapEntryCmp::new(cmp).with_stable(true)),
+        }
+    }
 }
 
 impl<'a, D, C> Stream for KMerge<'a, C, D>
@@ -160,3 +255,442 @@ where
         }
     }
 }
+
+impl<'a, D, C> KMerge<'a, C, D>
+where C: Compare<D>
+{
+    /// Treat the merged streams as sorted sets and produce their union, without duplicates.
+    ///
+    /// Every input stream must already be sorted according to `C`. A value repeated
+    /// within a single stream, or shared by several of them, is only emitted once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::iter;
+    /// use futures::StreamExt;
+    /// use stream_more::comparators::Ascending;
+    /// use stream_more::KMerge;
+    ///
+    /// let m = KMerge::by_cmp(Ascending).merge(iter([1, 2, 2, 3])).merge(iter([2, 3, 4])).union_distinct();
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![1, 2, 3, 4], got);
+    /// ```
+    pub fn union_distinct(self) -> UnionDistinct<'a, C, D> {
+        UnionDistinct {
+            kmerge: self,
+            pending: None,
+        }
+    }
+
+    /// Treat the merged streams as sorted sets and produce only the values present in
+    /// every input stream.
+    ///
+    /// Every input stream must already be sorted according to `C`. As soon as one of
+    /// the original streams is exhausted, nothing more can be common to all of them,
+    /// so no further values are emitted; the remaining streams are still drained to
+    /// their end before the output ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::iter;
+    /// use futures::StreamExt;
+    /// use stream_more::comparators::Ascending;
+    /// use stream_more::KMerge;
+    ///
+    /// let m = KMerge::by_cmp(Ascending).merge(iter([1, 2, 3])).merge(iter([2, 3, 4])).intersection();
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![2, 3], got);
+    /// ```
+    pub fn intersection(self) -> Intersection<'a, C, D> {
+        let live = self.heap.len();
+        Intersection {
+            kmerge: self,
+            live,
+            pending: None,
+            matched: HashSet::new(),
+        }
+    }
+
+    /// Fold together, with `f`, every run of consecutive popped items that compare
+    /// `Equal` under `C`, yielding a single item per run.
+    ///
+    /// Every input stream must already be sorted according to `C`, so that equal
+    /// elements from different streams end up adjacent in the popped order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::iter;
+    /// use futures::StreamExt;
+    /// use stream_more::comparators::by_key;
+    /// use stream_more::KMerge;
+    ///
+    /// // Sum the counts of equal-keyed entries coming from different streams, comparing
+    /// // only the first element of each pair.
+    /// let m = KMerge::by_cmp(by_key(|x: &(u64, u64)| &x.0))
+    ///     .merge(iter([(1, 1), (2, 1)]))
+    ///     .merge(iter([(1, 10), (3, 1)]))
+    ///     .coalesce_by(|acc: (u64, u64), item| (acc.0, acc.1 + item.1));
+    /// let got = block_on(m.collect::<Vec<_>>());
+    /// assert_eq!(vec![(1, 11), (2, 1), (3, 1)], got);
+    /// ```
+    pub fn coalesce_by<F>(self, f: F) -> CoalesceBy<'a, C, D, F>
+    where F: FnMut(D, D) -> D {
+        CoalesceBy {
+            kmerge: self,
+            pending: None,
+            f,
+        }
+    }
+
+    /// Drop duplicates: when several popped items compare `Equal` under `C`, keep only
+    /// the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::iter;
+    /// use futures::StreamExt;
+    /// use stream_more::comparators::Ascending;
+    /// use stream_more::KMerge;
+    ///
+    /// let m = KMerge::by_cmp(Ascending).merge(iter([1, 2, 2])).merge(iter([2, 3])).dedup();
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![1, 2, 3], got);
+    /// ```
+    pub fn dedup(self) -> CoalesceBy<'a, C, D, fn(D, D) -> D> {
+        self.coalesce_by(|acc, _next| acc)
+    }
+}
+
+/// A [`Stream`] adaptor, built by [`KMerge::union_distinct`], that merges several sorted
+/// streams as sets, i.e., without duplicates.
+pub struct UnionDistinct<'a, C, D>
+where C: Compare<D>
+{
+    kmerge: KMerge<'a, C, D>,
+
+    /// A value taken off the heap head, held back until it is known whether any other
+    /// entry currently holds an equal value.
+    pending: Option<D>,
+}
+
+impl<'a, D, C> Stream for UnionDistinct<'a, C, D>
+where
+    D: Unpin,
+    C: Compare<D> + Unpin,
+{
+    type Item = D;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let Some(mut peek_mut) = this.kmerge.heap.peek_mut() else {
+                return Poll::Ready(this.pending.take());
+            };
+
+            if !peek_mut.peeked.has_peeked() {
+                let next = ready!(peek_mut.stream.as_mut().poll_next(cx));
+                if let Some(t) = next {
+                    peek_mut.peeked = Peeked::Yes(t);
+                } else {
+                    PeekMut::pop(peek_mut);
+                }
+                continue;
+            }
+
+            let Some(pending) = this.pending.as_ref() else {
+                // No candidate yet: the head becomes the next one.
+                this.pending = peek_mut.peeked.take();
+                continue;
+            };
+
+            let is_dup = match &peek_mut.peeked {
+                Peeked::Yes(head) => this.kmerge.cmp.compare(head, pending) == Ordering::Equal,
+                Peeked::No => false,
+            };
+
+            if is_dup {
+                // Same set element as `pending`, drain it too and keep looking.
+                peek_mut.peeked.take();
+                continue;
+            }
+
+            return Poll::Ready(this.pending.take());
+        }
+    }
+}
+
+/// A [`Stream`] adaptor, built by [`KMerge::intersection`], that merges several sorted
+/// streams as sets, emitting only the values common to all of them.
+pub struct Intersection<'a, C, D>
+where C: Compare<D>
+{
+    kmerge: KMerge<'a, C, D>,
+
+    /// Number of streams originally merged in. Once any one of them is exhausted,
+    /// nothing can be common to all of them any more.
+    live: usize,
+
+    pending: Option<D>,
+
+    /// Ids (see `HeapEntry::id`) of the streams that have already matched `pending`
+    /// during the current round.
+    matched: HashSet<String>,
+}
+
+impl<'a, D, C> Stream for Intersection<'a, C, D>
+where
+    D: Unpin,
+    C: Compare<D> + Unpin,
+{
+    type Item = D;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let Some(mut peek_mut) = this.kmerge.heap.peek_mut() else {
+                // All streams are exhausted: flush `pending` if every stream had
+                // already matched it before the last one ended.
+                return Poll::Ready(if this.matched.len() == this.live {
+                    this.pending.take()
+                } else {
+                    None
+                });
+            };
+
+            if !peek_mut.peeked.has_peeked() {
+                let next = ready!(peek_mut.stream.as_mut().poll_next(cx));
+                if let Some(t) = next {
+                    peek_mut.peeked = Peeked::Yes(t);
+                } else {
+                    // A source stream is now permanently empty: no further candidate
+                    // can ever be common to every stream. But `pending` may already
+                    // have matched every *other* stream before this one ran out, so
+                    // it must still be flushed rather than silently dropped.
+                    PeekMut::pop(peek_mut);
+                    if this.matched.len() == this.live {
+                        return Poll::Ready(this.pending.take());
+                    }
+                    if this.kmerge.heap.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                }
+                continue;
+            }
+
+            if this.pending.is_none() {
+                this.matched.clear();
+                this.matched.insert(peek_mut.id.clone());
+                this.pending = peek_mut.peeked.take();
+                continue;
+            }
+
+            let pending = this.pending.as_ref().expect("checked above");
+            let is_match = match &peek_mut.peeked {
+                Peeked::Yes(head) => this.kmerge.cmp.compare(head, pending) == Ordering::Equal,
+                Peeked::No => false,
+            };
+
+            if is_match {
+                this.matched.insert(peek_mut.id.clone());
+                peek_mut.peeked.take();
+                continue;
+            }
+
+            drop(peek_mut);
+
+            let matched_all = this.matched.len() == this.live;
+            this.matched.clear();
+            let v = this.pending.take();
+
+            if matched_all {
+                return Poll::Ready(v);
+            }
+            // Not common to every stream: discard it and look for the next candidate.
+        }
+    }
+}
+
+/// A [`Stream`] adaptor, built by [`StreamMore::diff_by`], that emits the elements of the
+/// first of two sorted streams that are not present in the second.
+///
+/// [`StreamMore::diff_by`]: crate::stream_more::StreamMore::diff_by
+pub struct Diff<'a, C, D>
+where C: Compare<D>
+{
+    kmerge: KMerge<'a, C, D>,
+
+    /// The id (see `HeapEntry::id`) and value taken off the heap head, held back
+    /// until it is known whether the other stream currently holds an equal value.
+    pending: Option<(String, D)>,
+
+    /// Whether `pending` has already been found equal to something from the other
+    /// stream. Kept set (instead of discarding `pending` right away) so that any
+    /// further duplicates of the same value, from either stream, are drained along
+    /// with it rather than resurfacing as a fresh, unmatched candidate.
+    matched: bool,
+}
+
+impl<'a, C, D> Diff<'a, C, D>
+where C: Compare<D> + Clone
+{
+    pub(crate) fn new(
+        a: impl Stream<Item = D> + Send + 'a,
+        b: impl Stream<Item = D> + Send + 'a,
+        cmp: C,
+    ) -> Self
+    where
+        D: Send + 'a,
+    {
+        Diff {
+            kmerge: KMerge::by_cmp(cmp).merge(a).merge(b),
+            pending: None,
+            matched: false,
+        }
+    }
+}
+
+impl<'a, D, C> Stream for Diff<'a, C, D>
+where
+    D: Unpin,
+    C: Compare<D> + Unpin,
+{
+    type Item = D;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let Some(mut peek_mut) = this.kmerge.heap.peek_mut() else {
+                // Both streams are exhausted: flush a still-pending value from the
+                // first stream, since nothing in the second stream can match it any
+                // more.
+                return Poll::Ready(match this.pending.take() {
+                    Some((id, v)) if id == "1" && !this.matched => Some(v),
+                    _ => None,
+                });
+            };
+
+            if !peek_mut.peeked.has_peeked() {
+                let next = ready!(peek_mut.stream.as_mut().poll_next(cx));
+                if let Some(t) = next {
+                    peek_mut.peeked = Peeked::Yes(t);
+                } else {
+                    PeekMut::pop(peek_mut);
+                }
+                continue;
+            }
+
+            let Some((pending_id, pending_v)) = this.pending.as_ref() else {
+                let id = peek_mut.id.clone();
+                let v = peek_mut.peeked.take();
+                this.pending = v.map(|v| (id, v));
+                this.matched = false;
+                continue;
+            };
+
+            let is_equal = match &peek_mut.peeked {
+                Peeked::Yes(head) => this.kmerge.cmp.compare(head, pending_v) == Ordering::Equal,
+                Peeked::No => false,
+            };
+
+            if is_equal {
+                // A repeat of `pending`'s value, whether from its own source stream (a
+                // run of duplicates) or from the other one (the cross-stream match
+                // itself): either way it collapses into `pending` rather than becoming
+                // a candidate of its own. Once any occurrence has come from the other
+                // stream, `pending` is confirmed present in both and every further
+                // duplicate -- from either side -- must keep being drained instead of
+                // resurfacing as an unmatched value.
+                if peek_mut.id != *pending_id {
+                    this.matched = true;
+                }
+                peek_mut.peeked.take();
+                continue;
+            }
+
+            drop(peek_mut);
+
+            let (id, v) = this.pending.take().expect("checked above");
+            let matched = this.matched;
+            this.matched = false;
+            if id == "1" && !matched {
+                return Poll::Ready(Some(v));
+            }
+            // Either matched in both streams, or came from the second stream alone:
+            // not part of the first stream's difference, discard it and keep looking.
+        }
+    }
+}
+
+/// A [`Stream`] adaptor, built by [`KMerge::coalesce_by`] and [`KMerge::dedup`], that
+/// folds together consecutive equal-keyed items popped from the merge.
+pub struct CoalesceBy<'a, C, D, F>
+where C: Compare<D>
+{
+    kmerge: KMerge<'a, C, D>,
+
+    /// The run currently being folded, held back until it is known whether the next
+    /// popped item is also part of it.
+    pending: Option<D>,
+
+    f: F,
+}
+
+impl<'a, D, C, F> Stream for CoalesceBy<'a, C, D, F>
+where
+    D: Unpin,
+    C: Compare<D> + Unpin,
+    F: FnMut(D, D) -> D + Unpin,
+{
+    type Item = D;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let Some(mut peek_mut) = this.kmerge.heap.peek_mut() else {
+                return Poll::Ready(this.pending.take());
+            };
+
+            if !peek_mut.peeked.has_peeked() {
+                let next = ready!(peek_mut.stream.as_mut().poll_next(cx));
+                if let Some(t) = next {
+                    peek_mut.peeked = Peeked::Yes(t);
+                } else {
+                    PeekMut::pop(peek_mut);
+                }
+                continue;
+            }
+
+            let Some(pending) = this.pending.take() else {
+                this.pending = peek_mut.peeked.take();
+                continue;
+            };
+
+            let is_equal = match &peek_mut.peeked {
+                Peeked::Yes(head) => this.kmerge.cmp.compare(head, &pending) == Ordering::Equal,
+                Peeked::No => false,
+            };
+
+            if is_equal {
+                let Some(head) = peek_mut.peeked.take() else {
+                    unreachable!("checked above");
+                };
+                this.pending = Some((this.f)(pending, head));
+                continue;
+            }
+
+            return Poll::Ready(Some(pending));
+        }
+    }
+}
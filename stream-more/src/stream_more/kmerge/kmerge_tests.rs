@@ -9,6 +9,8 @@ use futures::stream::BoxStream;
 use futures::Stream;
 use futures::StreamExt;
 
+use crate::stream_more::comparators::by_key;
+use crate::stream_more::comparators::Ascending;
 use crate::stream_more::comparators::Descending;
 use crate::stream_more::kmerge::KMerge;
 use crate::stream_more::StreamMore;
@@ -19,7 +21,7 @@ fn test_ref_item() -> anyhow::Result<()> {
         i: u64,
     }
 
-    fn build_it(foo: &Foo) -> BoxStream<&u64> {
+    fn build_it(foo: &Foo) -> BoxStream<'_, &u64> {
         iter(vec![&foo.i]).boxed()
     }
 
@@ -194,3 +196,161 @@ fn test_continue_append_more_streams_after_polling() -> anyhow::Result<()> {
     assert_eq!(vec![1, 3, 4, 5], got);
     Ok(())
 }
+
+#[test]
+fn test_dedup() -> anyhow::Result<()> {
+    let x = iter([1, 2, 2]);
+    let y = iter([2, 3]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).dedup();
+    let got = block_on(z.collect::<Vec<u64>>());
+    assert_eq!(vec![1, 2, 3], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_coalesce_by() -> anyhow::Result<()> {
+    let x = iter([(1, 1), (2, 1)]);
+    let y = iter([(1, 10), (3, 1)]);
+
+    let z = KMerge::by_cmp(by_key(|p: &(u64, u64)| &p.0))
+        .merge(x)
+        .merge(y)
+        .coalesce_by(|acc: (u64, u64), item| (acc.0, acc.1 + item.1));
+    let got = block_on(z.collect::<Vec<_>>());
+    assert_eq!(vec![(1, 11), (2, 1), (3, 1)], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_coalesce_by_no_more_polling_after_none() -> anyhow::Result<()> {
+    let mut poll_results = vec![Poll::Ready(Some(1)), Poll::Ready(None), Poll::Ready(Some(9))];
+
+    let x = stream::poll_fn(move |_ctx: &mut Context<'_>| poll_results.remove(0));
+    let y = iter([1, 2]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).dedup();
+    let got = block_on(z.collect::<Vec<u64>>());
+    assert_eq!(vec![1, 2], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_stable_breaks_ties_by_merge_order() -> anyhow::Result<()> {
+    let x = iter([(1, "x0"), (2, "x1")]);
+    let y = iter([(1, "y0"), (2, "y1")]);
+    let w = iter([(1, "w0"), (2, "w1")]);
+
+    let z = KMerge::by_cmp(by_key(|p: &(u64, &str)| &p.0))
+        .merge(x)
+        .merge(y)
+        .merge(w)
+        .stable();
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(
+        vec![
+            (1, "x0"),
+            (1, "y0"),
+            (1, "w0"),
+            (2, "x1"),
+            (2, "y1"),
+            (2, "w1"),
+        ],
+        got
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stable_does_not_change_non_tied_order() -> anyhow::Result<()> {
+    let x = iter([2, 4, 5]);
+    let y = iter([1, 3, 6]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).stable();
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![1, 2, 3, 4, 5, 6], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_union_distinct_dedups_within_and_across_streams() -> anyhow::Result<()> {
+    let x = iter([1, 2, 2, 3]);
+    let y = iter([2, 3, 4]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).union_distinct();
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![1, 2, 3, 4], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_intersection_basic() -> anyhow::Result<()> {
+    let x = iter([1, 2, 3]);
+    let y = iter([2, 3, 4]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).intersection();
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![2, 3], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_intersection_single_common_element() -> anyhow::Result<()> {
+    let x = iter([2]);
+    let y = iter([2]);
+
+    let z = KMerge::by_cmp(Ascending).merge(x).merge(y).intersection();
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![2], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_by_basic() -> anyhow::Result<()> {
+    let z = iter([1, 2, 3, 4]).diff_by(iter([2, 4]), Ascending);
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![1, 3], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_by_collapses_duplicates_within_a_that_are_not_in_b() -> anyhow::Result<()> {
+    let z = iter([1, 1, 2, 3]).diff_by(iter([2]), Ascending);
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![1, 3], got);
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_by_drops_duplicates_within_a_that_are_also_in_b() -> anyhow::Result<()> {
+    // Every `1` in `a` is part of the same set element, and `b` has it too: the whole
+    // run must be dropped, not just the first occurrence.
+    let z = iter([1, 1, 2]).diff_by(iter([1]), Ascending);
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![2], got);
+
+    let z = iter([1, 1, 2]).diff_by(iter([1, 1]), Ascending);
+    let got = block_on(z.collect::<Vec<u64>>());
+
+    assert_eq!(vec![2], got);
+
+    Ok(())
+}
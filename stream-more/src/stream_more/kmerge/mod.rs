@@ -1,5 +1,9 @@
 pub(crate) mod heap_entry;
 #[allow(clippy::module_inception)] mod kmerge;
+pub use kmerge::CoalesceBy;
+pub use kmerge::Diff;
+pub use kmerge::Intersection;
 pub use kmerge::KMerge;
+pub use kmerge::UnionDistinct;
 
 #[cfg(test)] mod kmerge_tests;
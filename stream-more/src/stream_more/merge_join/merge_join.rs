@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::BoxStream;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::stream_more::peeked::Peeked;
+
+/// The outcome of joining two sorted streams at a given position: an item produced by
+/// only the left stream, only the right stream, or by both at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EitherOrBoth<A, B> {
+    /// An item only the left stream produced.
+    Left(A),
+
+    /// An item only the right stream produced.
+    Right(B),
+
+    /// A pair of items, one from each stream, that compared equal under `cmp`.
+    Both(A, B),
+}
+
+pin_project! {
+    /// A [`Stream`] that performs a full outer merge-join of two sorted streams, similar
+    /// to a SQL sorted merge-join.
+    ///
+    /// Unlike [`KMerge`], the two input streams may have different item types, and a
+    /// matched pair is surfaced together as [`EitherOrBoth::Both`] rather than flattened.
+    ///
+    /// Both input streams **should** already be sorted according to `cmp`, otherwise the
+    /// result is undefined.
+    ///
+    /// [`KMerge`]: crate::stream_more::kmerge::KMerge
+    pub struct MergeJoin<'a, A, B, F> {
+        #[pin]
+        peeked_a: Peeked<A>,
+        #[pin]
+        peeked_b: Peeked<B>,
+        stream_a: BoxStream<'a, A>,
+        stream_b: BoxStream<'a, B>,
+        done_a: bool,
+        done_b: bool,
+        cmp: F,
+    }
+}
+
+impl<'a, A, B, F> MergeJoin<'a, A, B, F>
+where F: FnMut(&A, &B) -> Ordering
+{
+    pub fn new(stream_a: BoxStream<'a, A>, stream_b: BoxStream<'a, B>, cmp: F) -> Self {
+        MergeJoin {
+            peeked_a: Peeked::No,
+            peeked_b: Peeked::No,
+            stream_a,
+            stream_b,
+            done_a: false,
+            done_b: false,
+            cmp,
+        }
+    }
+}
+
+impl<'a, A, B, F> Stream for MergeJoin<'a, A, B, F>
+where
+    A: Unpin,
+    B: Unpin,
+    F: FnMut(&A, &B) -> Ordering,
+{
+    type Item = EitherOrBoth<A, B>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if !*this.done_a && !this.peeked_a.has_peeked() {
+            match ready!(this.stream_a.as_mut().poll_next(cx)) {
+                Some(a) => *this.peeked_a = Peeked::Yes(a),
+                None => *this.done_a = true,
+            }
+        }
+
+        if !*this.done_b && !this.peeked_b.has_peeked() {
+            match ready!(this.stream_b.as_mut().poll_next(cx)) {
+                Some(b) => *this.peeked_b = Peeked::Yes(b),
+                None => *this.done_b = true,
+            }
+        }
+
+        match (this.peeked_a.has_peeked(), this.peeked_b.has_peeked()) {
+            (false, false) => Poll::Ready(None),
+            (true, false) => Poll::Ready(this.peeked_a.take().map(EitherOrBoth::Left)),
+            (false, true) => Poll::Ready(this.peeked_b.take().map(EitherOrBoth::Right)),
+            (true, true) => {
+                let ordering = match (&*this.peeked_a, &*this.peeked_b) {
+                    (Peeked::Yes(a), Peeked::Yes(b)) => (this.cmp)(a, b),
+                    _ => unreachable!("both sides were just confirmed peeked"),
+                };
+                match ordering {
+                    Ordering::Less => Poll::Ready(this.peeked_a.take().map(EitherOrBoth::Left)),
+                    Ordering::Greater => Poll::Ready(this.peeked_b.take().map(EitherOrBoth::Right)),
+                    Ordering::Equal => {
+                        let a = this.peeked_a.take().expect("checked above");
+                        let b = this.peeked_b.take().expect("checked above");
+                        Poll::Ready(Some(EitherOrBoth::Both(a, b)))
+                    }
+                }
+            }
+        }
+    }
+}
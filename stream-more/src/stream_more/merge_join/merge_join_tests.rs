@@ -0,0 +1,72 @@
+use std::task::Context;
+use std::task::Poll;
+
+use futures::executor::block_on;
+use futures::stream;
+use futures::stream::iter;
+use futures::StreamExt;
+
+use crate::stream_more::merge_join::EitherOrBoth;
+use crate::stream_more::merge_join::MergeJoin;
+
+#[test]
+fn test_merge_join_both_empty() -> anyhow::Result<()> {
+    let a = iter(Vec::<u64>::new());
+    let b = iter(Vec::<u64>::new());
+
+    let z = MergeJoin::new(a.boxed(), b.boxed(), |l: &u64, r: &u64| l.cmp(r));
+    let got = block_on(z.collect::<Vec<_>>());
+    assert_eq!(Vec::<EitherOrBoth<u64, u64>>::new(), got);
+    Ok(())
+}
+
+#[test]
+fn test_merge_join_basic() -> anyhow::Result<()> {
+    use EitherOrBoth::*;
+
+    let a = iter([1, 2, 4, 4]);
+    let b = iter([2, 3, 4]);
+
+    let z = MergeJoin::new(a.boxed(), b.boxed(), |l: &u64, r: &u64| l.cmp(r));
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(
+        vec![
+            Left(1), //
+            Both(2, 2),
+            Right(3),
+            Both(4, 4),
+            Left(4),
+        ],
+        got
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merge_join_drains_longer_side() -> anyhow::Result<()> {
+    use EitherOrBoth::*;
+
+    let a = iter([1]);
+    let b = iter([1, 2, 3]);
+
+    let z = MergeJoin::new(a.boxed(), b.boxed(), |l: &u64, r: &u64| l.cmp(r));
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(vec![Both(1, 1), Right(2), Right(3)], got);
+    Ok(())
+}
+
+#[test]
+fn test_merge_join_no_more_polling_after_none() -> anyhow::Result<()> {
+    let mut a_polls = vec![Poll::Ready(Some(1)), Poll::Ready(None), Poll::Ready(Some(99))];
+    let a = stream::poll_fn(move |_cx: &mut Context<'_>| a_polls.remove(0));
+
+    let b = iter([1, 2]);
+
+    let z = MergeJoin::new(a.boxed(), b.boxed(), |l: &u64, r: &u64| l.cmp(r));
+    let got = block_on(z.collect::<Vec<_>>());
+
+    assert_eq!(vec![EitherOrBoth::Both(1, 1), EitherOrBoth::Right(2)], got);
+    Ok(())
+}
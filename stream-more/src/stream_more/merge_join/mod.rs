@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)] mod merge_join;
+pub use merge_join::EitherOrBoth;
+pub use merge_join::MergeJoin;
+
+#[cfg(test)] mod merge_join_tests;
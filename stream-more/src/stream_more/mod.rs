@@ -4,12 +4,23 @@ use comparators::FnCmp;
 use compare::Compare;
 use futures::Stream;
 
+use crate::stream_more::batching::Batching;
+use crate::stream_more::batching::ChunkBy;
 use crate::stream_more::coalesce::Coalesce;
+use crate::stream_more::interleave::Interleave;
+use crate::stream_more::interleave::RoundRobin;
+use crate::stream_more::kmerge::Diff;
 use crate::stream_more::kmerge::KMerge;
+use crate::stream_more::merge_join::MergeJoin;
+use crate::stream_more::peekable::Peekable;
 
+pub mod batching;
 pub mod coalesce;
 pub mod comparators;
+pub mod interleave;
 pub mod kmerge;
+pub mod merge_join;
+pub mod peekable;
 pub mod peeked;
 
 /// Provide more methods for [`Stream`].
@@ -38,7 +49,7 @@ pub trait StreamMore: Stream {
     fn kmerge_by<'a, F>(self, first: F) -> KMerge<'a, FnCmp<F>, Self::Item>
     where
         Self: Sized + Send + 'a,
-        F: Fn(&Self::Item, &Self::Item) -> bool,
+        F: Fn(&Self::Item, &Self::Item) -> bool + Clone,
     {
         KMerge::by(first).merge(self)
     }
@@ -68,7 +79,7 @@ pub trait StreamMore: Stream {
     fn kmerge_by_cmp<'a, C>(self, cmp: C) -> KMerge<'a, C, Self::Item>
     where
         Self: Sized + Send + 'a,
-        C: Compare<Self::Item>,
+        C: Compare<Self::Item> + Clone,
     {
         KMerge::by_cmp(cmp).merge(self)
     }
@@ -158,6 +169,191 @@ pub trait StreamMore: Stream {
     {
         Coalesce::new(Box::pin(self), f)
     }
+
+    /// Treat this stream and `other` as sorted sets and return the elements of `self`
+    /// that are not present in `other`.
+    ///
+    /// Both streams must already be sorted according to `cmp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// use stream_more::comparators::Ascending;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let m = iter([1, 2, 3, 4]).diff_by(iter([2, 4]), Ascending);
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![1, 3], got);
+    /// ```
+    fn diff_by<'a, S2, C>(self, other: S2, cmp: C) -> Diff<'a, C, Self::Item>
+    where
+        Self: Sized + Send + 'a,
+        S2: Stream<Item = Self::Item> + Send + 'a,
+        C: Compare<Self::Item> + Clone,
+        Self::Item: Send + 'a,
+    {
+        Diff::new(self, other, cmp)
+    }
+
+    /// Perform a full outer merge-join of this stream with `other`, analogous to a SQL
+    /// sorted merge-join.
+    ///
+    /// Unlike [`KMerge`], the two streams may have different item types, and `cmp` is
+    /// called with one item from each side to decide how to pair them up: `Ordering::Less`
+    /// yields [`EitherOrBoth::Left`], `Ordering::Greater` yields [`EitherOrBoth::Right`],
+    /// and `Ordering::Equal` yields [`EitherOrBoth::Both`].
+    ///
+    /// Both streams **should** already be sorted according to `cmp`, otherwise the result
+    /// is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use stream_more::EitherOrBoth;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let m = iter([1, 2, 4]).merge_join_by(iter([2, 3]), |a: &u64, b: &u64| a.cmp(b));
+    /// let got = block_on(m.collect::<Vec<_>>());
+    /// assert_eq!(
+    ///     vec![
+    ///         EitherOrBoth::Left(1),
+    ///         EitherOrBoth::Both(2, 2),
+    ///         EitherOrBoth::Right(3),
+    ///         EitherOrBoth::Left(4),
+    ///     ],
+    ///     got
+    /// );
+    /// ```
+    fn merge_join_by<'a, S2, F>(self, other: S2, cmp: F) -> MergeJoin<'a, Self::Item, S2::Item, F>
+    where
+        Self: Sized + Send + 'a,
+        S2: Stream + Send + 'a,
+        F: FnMut(&Self::Item, &S2::Item) -> std::cmp::Ordering,
+    {
+        MergeJoin::new(Box::pin(self), Box::pin(other), cmp)
+    }
+
+    /// Create a [`Peekable`] adaptor that allows looking at the next item before
+    /// deciding whether to consume it.
+    ///
+    /// Named `peek_stream` rather than `peekable` to avoid colliding with
+    /// [`futures::StreamExt::peekable`], which every caller importing `StreamExt`
+    /// (the norm) already has in scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let mut s = iter([1, 2, 3]).peek_stream();
+    /// block_on(async {
+    ///     assert_eq!(Some(&1), s.peek().await);
+    ///     assert_eq!(Some(1), s.next().await);
+    /// });
+    /// ```
+    fn peek_stream<'a>(self) -> Peekable<'a, Self>
+    where Self: Sized + Send + 'a {
+        Peekable::new(Box::pin(self))
+    }
+
+    /// Fan-in this stream and `other` in round-robin order, without requiring either
+    /// side to be sorted.
+    ///
+    /// Unlike [`kmerge_by`](Self::kmerge_by), no comparator is needed: the two streams
+    /// are polled in turn, and a source that isn't ready yet simply lets the other one
+    /// go first. For more than two sources, or a different fairness policy, build an
+    /// [`Interleave`] directly with [`Interleave::with_strategy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let m = iter([1, 3, 5]).interleave(iter([2, 4]));
+    /// let got = block_on(m.collect::<Vec<u64>>());
+    /// assert_eq!(vec![2, 1, 4, 3, 5], got);
+    /// ```
+    fn interleave<'a, S2>(self, other: S2) -> Interleave<'a, Self::Item, RoundRobin>
+    where
+        Self: Sized + Send + 'a,
+        S2: Stream<Item = Self::Item> + Send + 'a,
+    {
+        Interleave::with_strategy(RoundRobin::default()).merge(self).merge(other)
+    }
+
+    /// Group consecutive items that share the same key into `(key, items)` pairs.
+    ///
+    /// `key_fn` is called once per item; a new group starts whenever the key changes,
+    /// and the last group is flushed once the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let m = iter([1, 1, 2, 2, 2, 1]).chunk_by(|x| *x);
+    /// let got = block_on(m.collect::<Vec<_>>());
+    /// assert_eq!(vec![(1, vec![1, 1]), (2, vec![2, 2, 2]), (1, vec![1])], got);
+    /// ```
+    fn chunk_by<'a, K, F>(self, key_fn: F) -> ChunkBy<'a, Self::Item, K, F>
+    where
+        Self: Sized + Send + 'a,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        ChunkBy::new(Box::pin(self), key_fn)
+    }
+
+    /// Repeatedly call `folder` to build arbitrary aggregates out of a run of items.
+    ///
+    /// `folder` is handed a [`Peekable`] view of the rest of the stream and returns
+    /// `Some(batch)` to emit one item, or `None` once there's nothing left to batch.
+    /// Because `folder` is a plain closure rather than an `async fn`, it must look ahead
+    /// and consume items through [`Peekable::peek_blocking`] and
+    /// [`Peekable::next_if_blocking`] instead of `.await`-ing [`Peekable::peek`].
+    ///
+    /// # Example
+    ///
+    /// Group the stream into runs of at most two items:
+    /// ```
+    /// use futures::stream::iter;
+    /// use futures::executor::block_on;
+    /// # use futures::StreamExt;
+    /// # use crate::stream_more::StreamMore;
+    ///
+    /// let m = iter([1, 2, 3, 4, 5]).batching(|p| {
+    ///     let first = p.next_if_blocking(|_| true)?;
+    ///     let mut batch = vec![first];
+    ///     if let Some(second) = p.next_if_blocking(|_| true) {
+    ///         batch.push(second);
+    ///     }
+    ///     Some(batch)
+    /// });
+    /// let got = block_on(m.collect::<Vec<_>>());
+    /// assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], got);
+    /// ```
+    fn batching<'a, F, B>(self, folder: F) -> Batching<'a, Self, F, B>
+    where
+        Self: Sized + Send + 'a,
+        F: FnMut(&mut Peekable<'a, Self>) -> Option<B>,
+    {
+        Batching::new(Box::pin(self), folder)
+    }
 }
 
 impl<T: ?Sized> StreamMore for T where T: Stream {}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)] mod peekable;
+pub use peekable::Peekable;
+pub use peekable::PeekingTakeWhile;
+
+#[cfg(test)] mod peekable_tests;
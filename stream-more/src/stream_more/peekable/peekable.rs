@@ -0,0 +1,190 @@
+use std::future::Future;
+use std::pin::pin;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::BoxStream;
+use futures::task::noop_waker_ref;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::stream_more::peeked::Peeked;
+
+/// Drive `fut` to completion on the current thread by busy-polling it with a no-op
+/// waker, without registering this thread as "inside an executor".
+///
+/// [`futures::executor::block_on`] would do the same, but it panics if called while
+/// already running inside another executor (e.g. from a [`Stream::poll_next`] that is
+/// itself being driven by `block_on` or a runtime) -- which is exactly the situation
+/// [`Peekable::peek_blocking`] and [`Peekable::next_if_blocking`] are used in.
+fn poll_to_completion<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(noop_waker_ref());
+    loop {
+        if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+            return v;
+        }
+    }
+}
+
+/// A [`Stream`] adaptor that allows looking at the next item without consuming it.
+///
+/// Built by [`StreamMore::peek_stream`].
+///
+/// [`StreamMore::peek_stream`]: crate::stream_more::StreamMore::peek_stream
+pub struct Peekable<'a, S>
+where S: Stream
+{
+    inner: BoxStream<'a, S::Item>,
+    peeked: Peeked<S::Item>,
+
+    /// Set once `inner` has returned `None`, so it is never polled again.
+    done: bool,
+}
+
+impl<'a, S> Peekable<'a, S>
+where S: Stream
+{
+    pub(crate) fn new(inner: BoxStream<'a, S::Item>) -> Self {
+        Peekable {
+            inner,
+            peeked: Peeked::No,
+            done: false,
+        }
+    }
+}
+
+impl<'a, S> Peekable<'a, S>
+where
+    S: Stream,
+    S::Item: Unpin,
+{
+    /// Return a reference to the next item without consuming it.
+    ///
+    /// Polls the inner stream only the first time it is called; subsequent calls, and a
+    /// following `poll_next()`, reuse the buffered value.
+    pub async fn peek(&mut self) -> Option<&S::Item> {
+        if !self.done && !self.peeked.has_peeked() {
+            match self.inner.next().await {
+                Some(v) => self.peeked = Peeked::Yes(v),
+                None => self.done = true,
+            }
+        }
+
+        match &self.peeked {
+            Peeked::Yes(v) => Some(v),
+            Peeked::No => None,
+        }
+    }
+
+    /// Consume and return the next item only if `pred` holds for it.
+    ///
+    /// If `pred` returns `false`, the item is left buffered and is returned by the next
+    /// call to `peek()`, `next_if()` or `poll_next()`.
+    pub async fn next_if(&mut self, pred: impl FnOnce(&S::Item) -> bool) -> Option<S::Item> {
+        self.peek().await?;
+
+        let matches = match &self.peeked {
+            Peeked::Yes(v) => pred(v),
+            Peeked::No => false,
+        };
+
+        if matches {
+            self.peeked.take()
+        } else {
+            None
+        }
+    }
+
+    /// Synchronous counterpart to [`Peekable::peek`].
+    ///
+    /// Useful inside a plain (non-`async`) closure, such as the folder passed to
+    /// [`StreamMore::batching`], which cannot itself contain an `.await`. Internally
+    /// this busy-polls [`Peekable::peek`] with a no-op waker rather than
+    /// [`futures::executor::block_on`], since the latter panics when called from
+    /// inside a `poll_next()` that is itself already being driven by an executor. It
+    /// is only appropriate for streams that resolve without genuinely waiting on an
+    /// external wake-up, e.g. `futures::stream::iter`.
+    ///
+    /// [`StreamMore::batching`]: crate::stream_more::StreamMore::batching
+    pub fn peek_blocking(&mut self) -> Option<&S::Item> {
+        poll_to_completion(self.peek())
+    }
+
+    /// Synchronous counterpart to [`Peekable::next_if`]. See [`Peekable::peek_blocking`]
+    /// for why and when this is appropriate to use.
+    pub fn next_if_blocking(&mut self, pred: impl FnOnce(&S::Item) -> bool) -> Option<S::Item> {
+        poll_to_completion(self.next_if(pred))
+    }
+
+    /// Return a [`Stream`] adaptor that yields items from `self` while `pred` holds.
+    ///
+    /// The first item for which `pred` returns `false` is **not** consumed: it stays
+    /// buffered in `self` and is available to whatever reads from `self` next.
+    pub fn peeking_take_while<P>(&mut self, pred: P) -> PeekingTakeWhile<'_, 'a, S, P>
+    where P: FnMut(&S::Item) -> bool {
+        PeekingTakeWhile { peekable: self, pred }
+    }
+}
+
+impl<'a, S> Stream for Peekable<'a, S>
+where
+    S: Stream,
+    S::Item: Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(v) = self.peeked.take() {
+            return Poll::Ready(Some(v));
+        }
+
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        let next = ready!(self.inner.as_mut().poll_next(cx));
+        if next.is_none() {
+            self.done = true;
+        }
+        Poll::Ready(next)
+    }
+}
+
+/// A [`Stream`] adaptor, built by [`Peekable::peeking_take_while`], that yields items
+/// while a predicate holds, leaving the first rejected item peekable in the underlying
+/// [`Peekable`].
+pub struct PeekingTakeWhile<'p, 'a, S, P>
+where S: Stream
+{
+    peekable: &'p mut Peekable<'a, S>,
+    pred: P,
+}
+
+impl<'p, 'a, S, P> Stream for PeekingTakeWhile<'p, 'a, S, P>
+where
+    S: Stream,
+    S::Item: Unpin,
+    P: FnMut(&S::Item) -> bool + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if !this.peekable.done && !this.peekable.peeked.has_peeked() {
+            let next = ready!(this.peekable.inner.as_mut().poll_next(cx));
+            match next {
+                Some(v) => this.peekable.peeked = Peeked::Yes(v),
+                None => this.peekable.done = true,
+            }
+        }
+
+        match &this.peekable.peeked {
+            Peeked::Yes(v) if (this.pred)(v) => Poll::Ready(this.peekable.peeked.take()),
+            _ => Poll::Ready(None),
+        }
+    }
+}
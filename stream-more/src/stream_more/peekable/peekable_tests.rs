@@ -0,0 +1,58 @@
+use futures::executor::block_on;
+use futures::stream::iter;
+use futures::StreamExt;
+
+use crate::stream_more::StreamMore;
+
+#[test]
+fn test_peek_does_not_consume() -> anyhow::Result<()> {
+    let mut p = iter([1, 2, 3]).peek_stream();
+
+    block_on(async {
+        assert_eq!(Some(&1), p.peek().await);
+        assert_eq!(Some(&1), p.peek().await);
+    });
+
+    let got = block_on(p.collect::<Vec<_>>());
+    assert_eq!(vec![1, 2, 3], got);
+    Ok(())
+}
+
+#[test]
+fn test_peek_on_empty_stream() -> anyhow::Result<()> {
+    let mut p = iter(Vec::<u64>::new()).peek_stream();
+
+    block_on(async {
+        assert_eq!(None, p.peek().await);
+        assert_eq!(None, p.peek().await);
+    });
+    Ok(())
+}
+
+#[test]
+fn test_next_if() -> anyhow::Result<()> {
+    let mut p = iter([1, 2, 3]).peek_stream();
+
+    block_on(async {
+        assert_eq!(None, p.next_if(|&x| x > 1).await);
+        assert_eq!(Some(1), p.next_if(|&x| x == 1).await);
+        assert_eq!(Some(2), p.next_if(|&x| x == 2).await);
+    });
+
+    let got = block_on(p.collect::<Vec<_>>());
+    assert_eq!(vec![3], got);
+    Ok(())
+}
+
+#[test]
+fn test_peeking_take_while() -> anyhow::Result<()> {
+    let mut p = iter([1, 2, 3, 10, 4]).peek_stream();
+
+    let got = block_on(p.peeking_take_while(|&x| x < 10).collect::<Vec<_>>());
+    assert_eq!(vec![1, 2, 3], got);
+
+    // The rejected item, `10`, is still available.
+    let rest = block_on(p.collect::<Vec<_>>());
+    assert_eq!(vec![10, 4], rest);
+    Ok(())
+}